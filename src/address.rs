@@ -0,0 +1,148 @@
+use crate::network::TargetNetwork;
+use bitcoin::hashes::Hash;
+use bitcoin::key::UntweakedPublicKey;
+use bitcoin::secp256k1::rand::thread_rng;
+use bitcoin::secp256k1::{All, Secp256k1, SecretKey};
+use bitcoin::{base58, Address, CompressedPublicKey, Network, PrivateKey, PublicKey};
+use regex::RegexBuilder;
+
+pub struct BitcoinAddress {
+    pub private_key: PrivateKey,
+    pub public_key: PublicKey,
+    pub address: String,
+}
+
+impl BitcoinAddress {
+    pub fn new(
+        secp: &Secp256k1<All>,
+        is_compressed: bool,
+        is_bech32: bool,
+        network: TargetNetwork,
+    ) -> Self {
+        let secret_key = SecretKey::new(&mut thread_rng());
+        let private_key = PrivateKey::new(secret_key, network.bitcoin_network().unwrap_or(Network::Bitcoin));
+        let public_key = PublicKey::from_private_key(secp, &private_key);
+        let public_key = if is_compressed || is_bech32 {
+            public_key
+        } else {
+            PublicKey {
+                compressed: false,
+                ..public_key
+            }
+        };
+
+        let address = if is_bech32 {
+            let compressed = CompressedPublicKey::from_private_key(secp, &private_key)
+                .expect("bech32 mode requires a compressed public key");
+            bech32_p2wpkh(&compressed, network)
+        } else {
+            base58_p2pkh(public_key, network)
+        };
+
+        Self {
+            private_key,
+            public_key,
+            address,
+        }
+    }
+
+    pub fn new_taproot(secp: &Secp256k1<All>, network: TargetNetwork) -> Self {
+        let bitcoin_network = network
+            .bitcoin_network()
+            .expect("taproot addresses are only supported on bitcoin networks");
+
+        let secret_key = SecretKey::new(&mut thread_rng());
+        let private_key = PrivateKey::new(secret_key, bitcoin_network);
+        let public_key = PublicKey::from_private_key(secp, &private_key);
+
+        let (internal_key, _parity) = UntweakedPublicKey::from_keypair(&secret_key.keypair(secp));
+        let address = Address::p2tr(secp, internal_key, None, bitcoin_network).to_string();
+
+        Self {
+            private_key,
+            public_key,
+            address,
+        }
+    }
+
+    pub fn new_p2sh_segwit(secp: &Secp256k1<All>, network: TargetNetwork) -> Self {
+        let secret_key = SecretKey::new(&mut thread_rng());
+        let private_key = PrivateKey::new(secret_key, network.bitcoin_network().unwrap_or(Network::Bitcoin));
+        let public_key = PublicKey::from_private_key(secp, &private_key);
+
+        let compressed = CompressedPublicKey::from_private_key(secp, &private_key)
+            .expect("nested segwit requires a compressed public key");
+        let address = base58_p2sh_segwit(&compressed, network);
+
+        Self {
+            private_key,
+            public_key,
+            address,
+        }
+    }
+
+    pub fn matches_with_any(&self, regexes: &[String], is_case_sensitive: bool) -> bool {
+        address_matches_with_any(&self.address, regexes, is_case_sensitive)
+    }
+}
+
+/// Encodes a P2PKH address, using `bitcoin::Address` for networks it
+/// natively understands and a manual base58check encoding for the rest
+/// (currently just Litecoin).
+fn base58_p2pkh(public_key: PublicKey, network: TargetNetwork) -> String {
+    match network.bitcoin_network() {
+        Some(bitcoin_network) => Address::p2pkh(public_key, bitcoin_network).to_string(),
+        None => {
+            let (version, _) = network.base58_versions();
+            let hash = public_key.pubkey_hash();
+            let mut payload = vec![version];
+            payload.extend_from_slice(hash.as_byte_array());
+            base58::encode_check(&payload)
+        }
+    }
+}
+
+/// Encodes a native-segwit P2WPKH address, using `bitcoin::Address` for
+/// networks it natively understands and a manual bech32 encoding for the
+/// rest (currently just Litecoin).
+fn bech32_p2wpkh(compressed: &CompressedPublicKey, network: TargetNetwork) -> String {
+    match network.bitcoin_network() {
+        Some(bitcoin_network) => Address::p2wpkh(compressed, bitcoin_network).to_string(),
+        None => {
+            let hrp = bech32::Hrp::parse(network.bech32_hrp()).expect("valid bech32 HRP");
+            let program = compressed.wpubkey_hash();
+            bech32::segwit::encode(hrp, bech32::Fe32::Q, program.as_byte_array())
+                .expect("valid witness program")
+        }
+    }
+}
+
+/// Encodes a nested-segwit (P2SH-P2WPKH) address: the `OP_0 <20-byte-keyhash>`
+/// redeem script is HASH160'd and base58check-encoded with the P2SH version
+/// byte. Uses `bitcoin::Address` for networks it natively understands and a
+/// manual encoding for the rest (currently just Litecoin).
+fn base58_p2sh_segwit(compressed: &CompressedPublicKey, network: TargetNetwork) -> String {
+    match network.bitcoin_network() {
+        Some(bitcoin_network) => Address::p2shwpkh(compressed, bitcoin_network).to_string(),
+        None => {
+            let (_, version) = network.base58_versions();
+            let mut redeem_script = vec![0x00, 0x14];
+            redeem_script.extend_from_slice(compressed.wpubkey_hash().as_byte_array());
+            let script_hash = bitcoin::hashes::hash160::Hash::hash(&redeem_script);
+
+            let mut payload = vec![version];
+            payload.extend_from_slice(script_hash.as_byte_array());
+            base58::encode_check(&payload)
+        }
+    }
+}
+
+pub fn address_matches_with_any(address: &str, regexes: &[String], is_case_sensitive: bool) -> bool {
+    regexes.iter().any(|pattern| {
+        RegexBuilder::new(pattern)
+            .case_insensitive(!is_case_sensitive)
+            .build()
+            .expect("Failed to build regex from pattern")
+            .is_match(address)
+    })
+}
@@ -0,0 +1,220 @@
+use crate::address::BitcoinAddress;
+use crate::network::TargetNetwork;
+use bitcoin::secp256k1::{All, Secp256k1};
+use regex::RegexBuilder;
+use std::time::{Duration, Instant};
+
+const BASE58_CHARSET_SIZE: f64 = 58.0;
+const BECH32_CHARSET_SIZE: f64 = 32.0;
+
+pub struct Estimate {
+    pub keys_per_second: f64,
+    pub expected_attempts: f64,
+    pub eta_50: Duration,
+    pub eta_95: Duration,
+}
+
+/// Measures throughput and difficulty for `pattern` and combines them into
+/// a 50th/95th percentile ETA for a search running across `num_threads`.
+///
+/// Difficulty is computed analytically for a fixed literal prefix (58 or 32
+/// possibilities per character depending on the address encoding, halved to
+/// `sqrt(charset_size)` for case-insensitive alphabetic positions); any
+/// other regex falls back to empirically measuring the hit rate over
+/// `sample`. Throughput is sampled on one thread and scaled by
+/// `num_threads`, matching how the real search spreads work across the
+/// rayon pool.
+#[allow(clippy::too_many_arguments)]
+pub fn estimate(
+    secp: &Secp256k1<All>,
+    network: TargetNetwork,
+    is_compressed: bool,
+    is_bech32: bool,
+    is_taproot: bool,
+    is_p2sh_segwit: bool,
+    pattern: &str,
+    is_case_sensitive: bool,
+    sample: Duration,
+    num_threads: usize,
+) -> Estimate {
+    let single_thread_keys_per_second = measure_keys_per_second(
+        secp,
+        network,
+        is_compressed,
+        is_bech32,
+        is_taproot,
+        is_p2sh_segwit,
+        sample,
+    );
+    let keys_per_second = single_thread_keys_per_second * num_threads as f64;
+
+    let expected_attempts = match literal_prefix(pattern) {
+        Some(prefix) => {
+            expected_attempts_for_prefix(prefix, is_bech32 || is_taproot, is_case_sensitive)
+        }
+        None => expected_attempts_empirical(
+            secp,
+            network,
+            is_compressed,
+            is_bech32,
+            is_taproot,
+            is_p2sh_segwit,
+            pattern,
+            is_case_sensitive,
+            sample,
+        ),
+    };
+
+    Estimate {
+        keys_per_second,
+        expected_attempts,
+        eta_50: attempts_to_duration(expected_attempts * 0.5_f64.ln().abs(), keys_per_second),
+        eta_95: attempts_to_duration(expected_attempts * 20.0_f64.ln(), keys_per_second),
+    }
+}
+
+fn generate_address(
+    secp: &Secp256k1<All>,
+    network: TargetNetwork,
+    is_compressed: bool,
+    is_bech32: bool,
+    is_taproot: bool,
+    is_p2sh_segwit: bool,
+) -> BitcoinAddress {
+    if is_taproot {
+        BitcoinAddress::new_taproot(secp, network)
+    } else if is_p2sh_segwit {
+        BitcoinAddress::new_p2sh_segwit(secp, network)
+    } else {
+        BitcoinAddress::new(secp, is_compressed, is_bech32, network)
+    }
+}
+
+fn measure_keys_per_second(
+    secp: &Secp256k1<All>,
+    network: TargetNetwork,
+    is_compressed: bool,
+    is_bech32: bool,
+    is_taproot: bool,
+    is_p2sh_segwit: bool,
+    sample: Duration,
+) -> f64 {
+    let start = Instant::now();
+    let mut attempts = 0u64;
+
+    while start.elapsed() < sample {
+        generate_address(secp, network, is_compressed, is_bech32, is_taproot, is_p2sh_segwit);
+        attempts += 1;
+    }
+
+    attempts as f64 / start.elapsed().as_secs_f64()
+}
+
+fn expected_attempts_for_prefix(prefix: &str, is_bech32_charset: bool, is_case_sensitive: bool) -> f64 {
+    let charset_size = if is_bech32_charset {
+        BECH32_CHARSET_SIZE
+    } else {
+        BASE58_CHARSET_SIZE
+    };
+
+    prefix
+        .chars()
+        .map(|c| {
+            if !is_case_sensitive && c.is_alphabetic() {
+                charset_size.sqrt()
+            } else {
+                charset_size
+            }
+        })
+        .product()
+}
+
+#[allow(clippy::too_many_arguments)]
+fn expected_attempts_empirical(
+    secp: &Secp256k1<All>,
+    network: TargetNetwork,
+    is_compressed: bool,
+    is_bech32: bool,
+    is_taproot: bool,
+    is_p2sh_segwit: bool,
+    pattern: &str,
+    is_case_sensitive: bool,
+    sample: Duration,
+) -> f64 {
+    let regex = RegexBuilder::new(pattern)
+        .case_insensitive(!is_case_sensitive)
+        .build()
+        .expect("Failed to build regex from pattern");
+
+    let start = Instant::now();
+    let mut attempts = 0u64;
+    let mut hits = 0u64;
+
+    while start.elapsed() < sample {
+        let address = generate_address(secp, network, is_compressed, is_bech32, is_taproot, is_p2sh_segwit);
+        attempts += 1;
+        if regex.is_match(&address.address) {
+            hits += 1;
+        }
+    }
+
+    if hits == 0 {
+        // No hits in the sample; report the sample size itself as a
+        // conservative lower bound rather than claiming infinite difficulty.
+        attempts as f64
+    } else {
+        attempts as f64 / hits as f64
+    }
+}
+
+/// A pattern is treated as a fixed literal prefix when it has no regex
+/// metacharacters once a leading `^` anchor is stripped.
+fn literal_prefix(pattern: &str) -> Option<&str> {
+    let trimmed = pattern.strip_prefix('^').unwrap_or(pattern);
+
+    if trimmed.is_empty() || trimmed.contains(|c: char| "\\.*+?[](){}|^$".contains(c)) {
+        None
+    } else {
+        Some(trimmed)
+    }
+}
+
+fn attempts_to_duration(attempts: f64, keys_per_second: f64) -> Duration {
+    if keys_per_second <= 0.0 {
+        return Duration::MAX;
+    }
+
+    Duration::try_from_secs_f64(attempts / keys_per_second).unwrap_or(Duration::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn expected_attempts_for_prefix_uses_the_bech32_alphabet_for_taproot_and_bech32() {
+        let base58 = expected_attempts_for_prefix("abc", false, true);
+        let bech32 = expected_attempts_for_prefix("abc", true, true);
+
+        assert_eq!(base58, BASE58_CHARSET_SIZE.powi(3));
+        assert_eq!(bech32, BECH32_CHARSET_SIZE.powi(3));
+    }
+
+    #[test]
+    fn expected_attempts_for_prefix_halves_entropy_for_case_insensitive_letters() {
+        let case_sensitive = expected_attempts_for_prefix("a", false, true);
+        let case_insensitive = expected_attempts_for_prefix("a", false, false);
+
+        assert_eq!(case_sensitive, BASE58_CHARSET_SIZE);
+        assert_eq!(case_insensitive, BASE58_CHARSET_SIZE.sqrt());
+    }
+
+    #[test]
+    fn attempts_to_duration_scales_inversely_with_throughput() {
+        let slow = attempts_to_duration(1000.0, 10.0);
+        let fast = attempts_to_duration(1000.0, 100.0);
+
+        assert_eq!(slow.as_secs_f64(), 100.0);
+        assert_eq!(fast.as_secs_f64(), 10.0);
+    }
+}
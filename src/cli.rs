@@ -0,0 +1,108 @@
+use clap::{Arg, ArgAction, ArgGroup, Command};
+
+pub fn prompt() -> Command {
+    Command::new("nakatoshi")
+        .about("A Bitcoin vanity address generator")
+        .arg(
+            Arg::new("regex")
+                .short('r')
+                .long("regex")
+                .help("A single regex pattern to search for"),
+        )
+        .arg(
+            Arg::new("input-file")
+                .short('f')
+                .long("input-file")
+                .help("A file containing one regex pattern per line"),
+        )
+        .group(
+            ArgGroup::new("pattern-source")
+                .args(["regex", "input-file"])
+                .required(true),
+        )
+        .arg(
+            Arg::new("case-sensitive")
+                .short('c')
+                .long("case-sensitive")
+                .action(ArgAction::SetTrue)
+                .help("Match patterns case-sensitively"),
+        )
+        .arg(
+            Arg::new("bech32")
+                .short('b')
+                .long("bech32")
+                .action(ArgAction::SetTrue)
+                .help("Generate native segwit (bc1q...) addresses"),
+        )
+        .arg(
+            Arg::new("taproot")
+                .short('t')
+                .long("taproot")
+                .action(ArgAction::SetTrue)
+                .conflicts_with("bech32")
+                .conflicts_with("p2sh-segwit")
+                .help("Generate single-key taproot (bc1p...) addresses"),
+        )
+        .arg(
+            Arg::new("p2sh-segwit")
+                .long("p2sh-segwit")
+                .action(ArgAction::SetTrue)
+                .conflicts_with("bech32")
+                .help("Generate nested segwit (3...) addresses"),
+        )
+        .arg(
+            Arg::new("uncompressed")
+                .short('u')
+                .long("uncompressed")
+                .action(ArgAction::SetTrue)
+                .help("Use an uncompressed public key for legacy addresses"),
+        )
+        .arg(
+            Arg::new("threads")
+                .long("threads")
+                .help("Number of worker threads to use (defaults to the number of CPUs)"),
+        )
+        .arg(
+            Arg::new("hd")
+                .long("hd")
+                .action(ArgAction::SetTrue)
+                .help("Search BIP39/BIP32 derived addresses instead of loose keys")
+                .conflicts_with("uncompressed")
+                .conflicts_with("p2sh-segwit")
+                .conflicts_with("estimate"),
+        )
+        .arg(
+            Arg::new("entropy-bits")
+                .long("entropy-bits")
+                .help("Mnemonic entropy in bits: 128 (12 words) or 256 (24 words)")
+                .default_value("128")
+                .requires("hd"),
+        )
+        .arg(
+            Arg::new("hd-children")
+                .long("hd-children")
+                .help("Number of external-chain addresses to scan per mnemonic")
+                .default_value("20")
+                .requires("hd"),
+        )
+        .arg(
+            Arg::new("network")
+                .long("network")
+                .value_parser(["mainnet", "testnet", "signet", "regtest", "litecoin"])
+                .default_value("mainnet")
+                .help("Network to mine addresses for"),
+        )
+        .arg(
+            Arg::new("estimate")
+                .long("estimate")
+                .action(ArgAction::SetTrue)
+                .help("Print throughput and an ETA for the pattern instead of searching"),
+        )
+        .arg(
+            Arg::new("sample-seconds")
+                .long("sample-seconds")
+                .help("How long to sample generation throughput/hit-rate for --estimate")
+                .default_value("3")
+                .requires("estimate"),
+        )
+}
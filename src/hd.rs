@@ -0,0 +1,86 @@
+use crate::address::address_matches_with_any;
+use crate::network::TargetNetwork;
+use bip39::Mnemonic;
+use bitcoin::bip32::{ChildNumber, DerivationPath, Xpriv};
+use bitcoin::secp256k1::{All, Secp256k1};
+use bitcoin::{Address, CompressedPublicKey, PrivateKey, PublicKey};
+
+const NATIVE_SEGWIT_PURPOSE: u32 = 84;
+const TAPROOT_PURPOSE: u32 = 86;
+
+pub struct HdCandidate {
+    pub mnemonic: String,
+    pub derivation_path: String,
+    pub private_key: PrivateKey,
+    pub public_key: PublicKey,
+    pub address: String,
+}
+
+impl HdCandidate {
+    pub fn matches_with_any(&self, regexes: &[String], is_case_sensitive: bool) -> bool {
+        address_matches_with_any(&self.address, regexes, is_case_sensitive)
+    }
+}
+
+/// Generates a fresh BIP39 mnemonic and returns the first `num_children`
+/// external-chain addresses (`m/84'|86'/0'/0'/0/i`) derived from it.
+pub fn generate_candidates(
+    secp: &Secp256k1<All>,
+    network: TargetNetwork,
+    entropy_bits: usize,
+    num_children: u32,
+    is_taproot: bool,
+) -> Vec<HdCandidate> {
+    let bitcoin_network = network
+        .bitcoin_network()
+        .expect("hd mode does not yet support litecoin");
+
+    let word_count = entropy_bits / 32 * 3;
+    let mnemonic = Mnemonic::generate(word_count).expect("Failed to generate BIP39 mnemonic");
+    let seed = mnemonic.to_seed("");
+    let master = Xpriv::new_master(bitcoin_network, &seed).expect("Failed to derive master key");
+
+    let purpose = if is_taproot {
+        TAPROOT_PURPOSE
+    } else {
+        NATIVE_SEGWIT_PURPOSE
+    };
+    let account_path = DerivationPath::from(vec![
+        ChildNumber::from_hardened_idx(purpose).unwrap(),
+        ChildNumber::from_hardened_idx(0).unwrap(),
+        ChildNumber::from_hardened_idx(0).unwrap(),
+        ChildNumber::from_normal_idx(0).unwrap(),
+    ]);
+    let account_xpriv = master
+        .derive_priv(secp, &account_path)
+        .expect("Failed to derive account key");
+
+    (0..num_children)
+        .map(|index| {
+            let child_number = ChildNumber::from_normal_idx(index).unwrap();
+            let child = account_xpriv
+                .derive_priv(secp, &[child_number])
+                .expect("Failed to derive child key");
+
+            let private_key = child.to_priv();
+            let public_key = PublicKey::from_private_key(secp, &private_key);
+
+            let address = if is_taproot {
+                let (internal_key, _parity) = child.to_keypair(secp).x_only_public_key();
+                Address::p2tr(secp, internal_key, None, bitcoin_network).to_string()
+            } else {
+                let compressed = CompressedPublicKey::from_private_key(secp, &private_key)
+                    .expect("hd mode requires a compressed public key");
+                Address::p2wpkh(&compressed, bitcoin_network).to_string()
+            };
+
+            HdCandidate {
+                mnemonic: mnemonic.to_string(),
+                derivation_path: format!("m/{purpose}'/0'/0'/0/{index}"),
+                private_key,
+                public_key,
+                address,
+            }
+        })
+        .collect()
+}
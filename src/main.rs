@@ -7,9 +7,14 @@ use std::io::BufRead;
 use std::io::BufReader;
 
 mod address;
+mod bench;
 mod cli;
+mod hd;
+mod network;
 
 use address::BitcoinAddress;
+use network::TargetNetwork;
+use std::str::FromStr;
 
 fn main() {
     let matches = cli::prompt().get_matches();
@@ -17,7 +22,33 @@ fn main() {
 
     let is_case_sensitive = matches.get_flag("case-sensitive");
     let is_bech32 = matches.get_flag("bech32");
+    let is_taproot = matches.get_flag("taproot");
+    let is_p2sh_segwit = matches.get_flag("p2sh-segwit");
     let is_compressed = !matches.get_flag("uncompressed");
+    let is_hd = matches.get_flag("hd");
+
+    let network = matches
+        .get_one::<String>("network")
+        .map(|network| TargetNetwork::from_str(network).expect("Unsupported network"))
+        .unwrap_or(TargetNetwork::Mainnet);
+
+    if is_taproot && network.bitcoin_network().is_none() {
+        cli::prompt()
+            .error(
+                clap::error::ErrorKind::ArgumentConflict,
+                format!("--taproot does not support --network {network}"),
+            )
+            .exit();
+    }
+
+    if is_hd && network.bitcoin_network().is_none() {
+        cli::prompt()
+            .error(
+                clap::error::ErrorKind::ArgumentConflict,
+                format!("--hd does not yet support --network {network}"),
+            )
+            .exit();
+    }
 
     let num_threads = matches
         .get_one::<String>("threads")
@@ -32,6 +63,37 @@ fn main() {
         }
     };
 
+    if matches.get_flag("estimate") {
+        let sample_seconds = matches
+            .get_one::<String>("sample-seconds")
+            .and_then(|seconds| seconds.parse().ok())
+            .unwrap_or(3);
+
+        let estimate = bench::estimate(
+            &secp,
+            network,
+            is_compressed,
+            is_bech32,
+            is_taproot,
+            is_p2sh_segwit,
+            &regexes[0],
+            is_case_sensitive,
+            std::time::Duration::from_secs(sample_seconds),
+            num_threads,
+        );
+
+        let result = json!({
+            "pattern": regexes[0],
+            "keys_per_second": estimate.keys_per_second,
+            "expected_attempts": estimate.expected_attempts,
+            "eta_50_seconds": estimate.eta_50.as_secs_f64(),
+            "eta_95_seconds": estimate.eta_95.as_secs_f64(),
+        });
+
+        print!("{}", result);
+        return;
+    }
+
     let rayon_pool = rayon::ThreadPoolBuilder::new()
         .num_threads(num_threads)
         .build()
@@ -44,23 +106,58 @@ fn main() {
     progress.set_style(template);
     // progress.set_draw_rate(10);
 
-    let bitcoin_address: BitcoinAddress = rayon_pool.install(|| {
-        rayon::iter::repeat(BitcoinAddress::new)
-            .inspect(|_| progress.inc(1))
-            .map(|create| create(&secp, is_compressed, is_bech32))
-            .find_any(|address| address.matches_with_any(&regexes, is_case_sensitive))
-            .expect("Failed to find Bitcoin address match")
-    });
+    let result = if is_hd {
+        let entropy_bits = matches
+            .get_one::<String>("entropy-bits")
+            .and_then(|bits| bits.parse().ok())
+            .unwrap_or(128);
+        let num_children = matches
+            .get_one::<String>("hd-children")
+            .and_then(|children| children.parse().ok())
+            .unwrap_or(20);
 
-    let attempts = progress.position();
-    progress.finish_and_clear();
+        let candidate = rayon_pool.install(|| {
+            rayon::iter::repeat(())
+                .flat_map_iter(|_| hd::generate_candidates(&secp, network, entropy_bits, num_children, is_taproot))
+                .inspect(|_| progress.inc(1))
+                .find_any(|candidate| candidate.matches_with_any(&regexes, is_case_sensitive))
+                .expect("Failed to find Bitcoin address match")
+        });
+
+        json!({
+            "mnemonic": candidate.mnemonic,
+            "derivation_path": candidate.derivation_path,
+            "private_key": candidate.private_key.to_string(),
+            "public_key": candidate.public_key.to_string(),
+            "address": candidate.address.to_string(),
+            "attempts": progress.position()
+        })
+    } else {
+        let bitcoin_address: BitcoinAddress = rayon_pool.install(|| {
+            rayon::iter::repeat(())
+                .inspect(|_| progress.inc(1))
+                .map(|_| {
+                    if is_taproot {
+                        BitcoinAddress::new_taproot(&secp, network)
+                    } else if is_p2sh_segwit {
+                        BitcoinAddress::new_p2sh_segwit(&secp, network)
+                    } else {
+                        BitcoinAddress::new(&secp, is_compressed, is_bech32, network)
+                    }
+                })
+                .find_any(|address| address.matches_with_any(&regexes, is_case_sensitive))
+                .expect("Failed to find Bitcoin address match")
+        });
 
-    let result = json!({
-        "private_key": bitcoin_address.private_key.to_string(),
-        "public_key": bitcoin_address.public_key.to_string(),
-        "address": bitcoin_address.address.to_string(),
-        "attempts": attempts
-    });
+        json!({
+            "private_key": bitcoin_address.private_key.to_string(),
+            "public_key": bitcoin_address.public_key.to_string(),
+            "address": bitcoin_address.address.to_string(),
+            "attempts": progress.position()
+        })
+    };
+
+    progress.finish_and_clear();
 
     print!("{}", result);
 }
@@ -81,6 +178,7 @@ fn get_regexes_from_file(file_name: &str) -> Vec<String> {
 #[cfg(test)]
 mod tests {
     use crate::address::BitcoinAddress;
+    use crate::network::TargetNetwork;
     use bitcoin::secp256k1::Secp256k1;
 
     #[test]
@@ -88,7 +186,7 @@ mod tests {
         let secp = Secp256k1::new();
         let is_bech32 = false;
         let is_compressed = true;
-        let bitcoin_address = BitcoinAddress::new(&secp, is_compressed, is_bech32);
+        let bitcoin_address = BitcoinAddress::new(&secp, is_compressed, is_bech32, TargetNetwork::Mainnet);
 
         let actual = bitcoin_address.public_key.to_string().len();
         let expected = 66;
@@ -101,7 +199,7 @@ mod tests {
         let secp = Secp256k1::new();
         let is_bech32 = false;
         let is_compressed = false;
-        let bitcoin_address = BitcoinAddress::new(&secp, is_compressed, is_bech32);
+        let bitcoin_address = BitcoinAddress::new(&secp, is_compressed, is_bech32, TargetNetwork::Mainnet);
 
         let actual = bitcoin_address.public_key.to_string().len();
         let expected = 130;
@@ -114,18 +212,46 @@ mod tests {
         let secp = Secp256k1::new();
         let is_bech32 = true;
         let is_compressed = true;
-        let bitcoin_address = BitcoinAddress::new(&secp, is_compressed, is_bech32);
+        let bitcoin_address = BitcoinAddress::new(&secp, is_compressed, is_bech32, TargetNetwork::Mainnet);
         let address = bitcoin_address.address.to_string();
 
         assert!(address.starts_with("bc1q"));
     }
 
+    #[test]
+    fn create_taproot_address() {
+        let secp = Secp256k1::new();
+        let bitcoin_address = BitcoinAddress::new_taproot(&secp, TargetNetwork::Mainnet);
+
+        assert!(bitcoin_address.address.starts_with("bc1p"));
+    }
+
+    #[test]
+    fn create_litecoin_addresses() {
+        let secp = Secp256k1::new();
+        let is_compressed = true;
+
+        let legacy = BitcoinAddress::new(&secp, is_compressed, false, TargetNetwork::Litecoin);
+        assert!(legacy.address.starts_with('L'));
+
+        let bech32 = BitcoinAddress::new(&secp, is_compressed, true, TargetNetwork::Litecoin);
+        assert!(bech32.address.starts_with("ltc1q"));
+    }
+
+    #[test]
+    fn create_p2sh_segwit_address() {
+        let secp = Secp256k1::new();
+        let bitcoin_address = BitcoinAddress::new_p2sh_segwit(&secp, TargetNetwork::Mainnet);
+
+        assert!(bitcoin_address.address.starts_with('3'));
+    }
+
     #[test]
     fn create_bitcoin_private_key() {
         let secp = Secp256k1::new();
         let is_bech32 = false;
         let is_compressed = true;
-        let bitcoin_address = BitcoinAddress::new(&secp, is_compressed, is_bech32);
+        let bitcoin_address = BitcoinAddress::new(&secp, is_compressed, is_bech32, TargetNetwork::Mainnet);
 
         let actual = bitcoin_address.private_key.to_string().len();
         let expected = 52;
@@ -138,11 +264,31 @@ mod tests {
         let secp = Secp256k1::new();
         let is_bech32 = false;
         let is_compressed = true;
-        let bitcoin_address = BitcoinAddress::new(&secp, is_compressed, is_bech32);
+        let bitcoin_address = BitcoinAddress::new(&secp, is_compressed, is_bech32, TargetNetwork::Mainnet);
 
         let actual = bitcoin_address.address.to_string().len();
         let expected = 34;
 
         assert_eq!(actual, expected);
     }
+
+    #[test]
+    fn hd_generate_candidates_expands_one_mnemonic_into_many_addresses() {
+        let secp = Secp256k1::new();
+        let entropy_bits = 128;
+        let num_children = 20;
+        let is_taproot = false;
+
+        let candidates = crate::hd::generate_candidates(
+            &secp,
+            TargetNetwork::Mainnet,
+            entropy_bits,
+            num_children,
+            is_taproot,
+        );
+
+        assert_eq!(candidates.len(), num_children as usize);
+        assert_eq!(candidates[0].derivation_path, "m/84'/0'/0'/0/0");
+        assert_eq!(candidates[19].derivation_path, "m/84'/0'/0'/0/19");
+    }
 }
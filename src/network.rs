@@ -0,0 +1,73 @@
+use std::fmt;
+
+/// The set of networks that vanity addresses can be mined for.
+///
+/// Bitcoin's own networks map directly onto `bitcoin::Network`; Litecoin
+/// is not a variant of that enum, so its base58/bech32 parameters are
+/// encoded here instead.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TargetNetwork {
+    Mainnet,
+    Testnet,
+    Signet,
+    Regtest,
+    Litecoin,
+}
+
+impl TargetNetwork {
+    pub fn bitcoin_network(self) -> Option<bitcoin::Network> {
+        match self {
+            TargetNetwork::Mainnet => Some(bitcoin::Network::Bitcoin),
+            TargetNetwork::Testnet => Some(bitcoin::Network::Testnet),
+            TargetNetwork::Signet => Some(bitcoin::Network::Signet),
+            TargetNetwork::Regtest => Some(bitcoin::Network::Regtest),
+            TargetNetwork::Litecoin => None,
+        }
+    }
+
+    /// base58check version bytes as `(p2pkh, p2sh)`. Only used for networks
+    /// that `bitcoin::Address` doesn't already know how to serialize.
+    pub fn base58_versions(self) -> (u8, u8) {
+        match self {
+            TargetNetwork::Litecoin => (0x30, 0x32),
+            _ => unreachable!("base58_versions is only used for non-bitcoin networks"),
+        }
+    }
+
+    /// bech32/bech32m human-readable part. Only used for networks that
+    /// `bitcoin::Address` doesn't already know how to serialize.
+    pub fn bech32_hrp(self) -> &'static str {
+        match self {
+            TargetNetwork::Litecoin => "ltc",
+            _ => unreachable!("bech32_hrp is only used for non-bitcoin networks"),
+        }
+    }
+}
+
+impl fmt::Display for TargetNetwork {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            TargetNetwork::Mainnet => "mainnet",
+            TargetNetwork::Testnet => "testnet",
+            TargetNetwork::Signet => "signet",
+            TargetNetwork::Regtest => "regtest",
+            TargetNetwork::Litecoin => "litecoin",
+        };
+        f.write_str(name)
+    }
+}
+
+impl std::str::FromStr for TargetNetwork {
+    type Err = String;
+
+    fn from_str(network: &str) -> Result<Self, Self::Err> {
+        match network {
+            "mainnet" => Ok(TargetNetwork::Mainnet),
+            "testnet" => Ok(TargetNetwork::Testnet),
+            "signet" => Ok(TargetNetwork::Signet),
+            "regtest" => Ok(TargetNetwork::Regtest),
+            "litecoin" => Ok(TargetNetwork::Litecoin),
+            other => Err(format!("Unknown network: {other}")),
+        }
+    }
+}